@@ -0,0 +1,57 @@
+use ark_bn254::{Bn254, Fr};
+use ark_ec::PairingEngine;
+use ark_ff::UniformRand;
+use ark_poly_commit::{LabeledPolynomial, PolynomialCommitment};
+use blake2::Blake2s;
+use commitment_equivalence::{HyraxPC, MultilinearExtension, PolyCommitEquivalence};
+use rand::thread_rng;
+use std::iter;
+
+// Shorthand for the concrete instances of the types we use. Hyrax is the same transparent,
+// Pedersen-based multilinear scheme on both sides here, since no second multilinear scheme
+// is available in this crate's pinned arkworks version to pair it with.
+type Hyrax = HyraxPC<<Bn254 as PairingEngine>::G1Affine>;
+type PCEquivalence =
+    PolyCommitEquivalence<Blake2s, Bn254, MultilinearExtension<Fr>, Hyrax, Hyrax>;
+
+fn main() {
+    let rng = &mut thread_rng();
+    let num_vars = 4;
+    let max_hiding = 1;
+
+    // Random multilinear polynomial over `num_vars` variables
+    let evaluations: Vec<Fr> = (0..1 << num_vars).map(|_| Fr::rand(rng)).collect();
+    let poly = MultilinearExtension::from_evaluations(num_vars, evaluations);
+    let poly = LabeledPolynomial::new(String::from("poly"), poly, None, None);
+
+    // Setup (two independent Hyrax parameter sets, standing in for two distinct schemes)
+    let pp_1 = Hyrax::setup(num_vars, None, rng).unwrap();
+    let (ck_1, vk_1) = Hyrax::trim(&pp_1, num_vars, max_hiding, None).unwrap();
+
+    let pp_2 = Hyrax::setup(num_vars, None, rng).unwrap();
+    let (ck_2, vk_2) = Hyrax::trim(&pp_2, num_vars, max_hiding, None).unwrap();
+
+    // Commit to the polynomial under both parameter sets
+    let (commit_1, rand_1) = Hyrax::commit(&ck_1, iter::once(&poly), Some(rng)).unwrap();
+    let (commit_2, rand_2) = Hyrax::commit(&ck_2, iter::once(&poly), Some(rng)).unwrap();
+
+    // Proof of equivalence
+    let proof = PCEquivalence::prove(
+        (&ck_1, &ck_2),
+        (&vk_1, &vk_2),
+        &poly,
+        (&commit_1[0], &commit_2[0]),
+        (&rand_1[0], &rand_2[0]),
+        num_vars,
+        3,
+    )
+    .unwrap();
+
+    // Verify proof
+    match PCEquivalence::verify((&vk_1, &vk_2), (&commit_1[0], &commit_2[0]), proof, num_vars, 3)
+        .is_ok()
+    {
+        true => println!("The proof is valid"),
+        false => println!("The proof is not valid"),
+    }
+}
@@ -37,14 +37,17 @@ fn main() {
     // Proof of equivalence
     let proof = PCEquivalence::prove(
         (&kzg_ck, &ipa_ck),
+        (&kzg_vk, &ipa_vk),
         &poly,
         (&kzg_commit[0], &ipa_commit[0]),
         (&kzg_rand[0], &ipa_rand[0]),
+        0,
+        3,
     )
     .unwrap();
 
     // Verify proof
-    match PCEquivalence::verify((&kzg_vk, &ipa_vk), (&kzg_commit[0], &ipa_commit[0]), proof).is_ok() {
+    match PCEquivalence::verify((&kzg_vk, &ipa_vk), (&kzg_commit[0], &ipa_commit[0]), proof, 0, 3).is_ok() {
         true => println!("The proof is valid"),
         false => println!("The proof is not valid")
     }
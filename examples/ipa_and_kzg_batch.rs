@@ -0,0 +1,59 @@
+use ark_bn254::{Bn254, Fr};
+use ark_ec::PairingEngine;
+use ark_poly::{univariate::DensePolynomial, UVPolynomial};
+use ark_poly_commit::{
+    ipa_pc::InnerProductArgPC, sonic_pc::SonicKZG10, LabeledPolynomial, PolynomialCommitment,
+};
+use blake2::Blake2s;
+use commitment_equivalence::PolyCommitEquivalence;
+use rand::thread_rng;
+
+// Shorthand for the concrete instances of the types we use
+type KZG = SonicKZG10<Bn254, DensePolynomial<Fr>>;
+type IPA = InnerProductArgPC<<Bn254 as PairingEngine>::G1Affine, Blake2s, DensePolynomial<Fr>>;
+type PCEquivalence = PolyCommitEquivalence<Blake2s, Bn254, DensePolynomial<Fr>, KZG, IPA>;
+
+fn main() {
+    let rng = &mut thread_rng();
+    let max_degree = 20;
+    let max_hiding = 1;
+    let batch_size = 4;
+
+    // A batch of random polynomials
+    let polys: Vec<_> = (0..batch_size)
+        .map(|i| {
+            let poly: DensePolynomial<Fr> = DensePolynomial::rand(max_degree - 1, rng);
+            LabeledPolynomial::new(format!("poly-{}", i), poly, Some(max_degree), Some(1))
+        })
+        .collect();
+
+    // Setup commitment schemes
+    let kzg_pp = KZG::setup(max_degree, None, rng).unwrap();
+    let (kzg_ck, kzg_vk) = KZG::trim(&kzg_pp, max_degree, max_hiding, Some(&[max_degree])).unwrap();
+
+    let ipa_pp = IPA::setup(max_degree, None, rng).unwrap();
+    let (ipa_ck, ipa_vk) = IPA::trim(&ipa_pp, max_degree, max_hiding, Some(&[max_degree])).unwrap();
+
+    // Commit to the whole batch with both schemes
+    let (kzg_commits, kzg_rands) = KZG::commit(&kzg_ck, polys.iter(), Some(rng)).unwrap();
+    let (ipa_commits, ipa_rands) = IPA::commit(&ipa_ck, polys.iter(), Some(rng)).unwrap();
+
+    // A single proof of equivalence for the whole batch
+    let proof = PCEquivalence::prove_batch(
+        (&kzg_ck, &ipa_ck),
+        (&kzg_vk, &ipa_vk),
+        &polys,
+        (&kzg_commits, &ipa_commits),
+        (&kzg_rands, &ipa_rands),
+        0,
+    )
+    .unwrap();
+
+    // Verify the batch proof
+    match PCEquivalence::verify_batch((&kzg_vk, &ipa_vk), (&kzg_commits, &ipa_commits), proof, 0)
+        .is_ok()
+    {
+        true => println!("The batch proof is valid"),
+        false => println!("The batch proof is not valid"),
+    }
+}
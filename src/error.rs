@@ -7,6 +7,11 @@ pub enum Error {
     KZGFailed,
     IPAFailed,
     PCError { error: String },
+    /// The slices of polynomials, commitments and randomnesses passed to a batch
+    /// operation do not all have the same length, or a proof passed to `verify` is empty,
+    /// holds fewer points than the required security level, or has evaluation and opening
+    /// counts that differ.
+    BatchSizeMismatch,
 }
 
 impl From<io::Error> for Error {
@@ -22,10 +27,11 @@ impl From<ark_poly_commit::Error> for Error {
 }
 
 /// Convert an ark_poly_commit error
-pub fn from_pc_error<F, PC>(error: PC::Error) -> Error
+pub fn from_pc_error<F, P, PC>(error: PC::Error) -> Error
 where
     F: ark_ff::Field,
-    PC: ark_poly_commit::PolynomialCommitment<F, ark_poly::univariate::DensePolynomial<F>>,
+    P: ark_poly::Polynomial<F>,
+    PC: ark_poly_commit::PolynomialCommitment<F, P>,
 {
     println!("Polynomial Commitment Error: {:?}", error);
     Error::PCError {
@@ -0,0 +1,391 @@
+use crate::multilinear::MultilinearExtension;
+use ark_ec::msm::VariableBaseMSM;
+use ark_ec::{AffineCurve, ProjectiveCurve};
+use ark_ff::{PrimeField, UniformRand, Zero};
+use ark_poly_commit::{
+    LabeledCommitment, LabeledPolynomial, PCCommitment, PCCommitterKey, PCPreparedCommitment,
+    PCPreparedVerifierKey, PCProof, PCRandomness, PCUniversalParams, PCVerifierKey,
+    PolynomialCommitment,
+};
+use ark_serialize::{CanonicalDeserialize, CanonicalSerialize, SerializationError};
+use ark_std::io::{Read, Write};
+use ark_std::marker::PhantomData;
+use ark_std::rand::RngCore;
+use ark_std::vec::Vec;
+use core::fmt;
+
+/// A transparent, Pedersen-based commitment to a [`MultilinearExtension`], following Hyrax's
+/// matrix decomposition: a polynomial over `n` variables is laid out as a `2^⌈n/2⌉ x 2^⌊n/2⌋`
+/// matrix, one Pedersen vector commitment per row. An opening at `point` reveals the
+/// row-combination vector `y = L^T M` (where `L` is the equality tensor of the "row" half of
+/// `point`) along with its combined blinding, so both the commitment and the claimed
+/// evaluation can be checked against `y` directly, without a further succinctness round.
+pub struct HyraxPC<G: AffineCurve> {
+    _group: PhantomData<G>,
+}
+
+fn split_num_vars(num_vars: usize) -> (usize, usize) {
+    let col_vars = num_vars / 2;
+    let row_vars = num_vars - col_vars;
+    (1 << row_vars, 1 << col_vars)
+}
+
+/// The equality-polynomial tensor of `vars`: a vector of length `2^vars.len()` whose `i`-th
+/// entry is `prod_j (vars[j] if bit_j(i) == 1 else 1 - vars[j])`.
+fn eq_tensor<F: PrimeField>(vars: &[F]) -> Vec<F> {
+    let mut table = vec![F::one()];
+    for x in vars {
+        let mut next = Vec::with_capacity(table.len() * 2);
+        next.extend(table.iter().map(|c| *c * (F::one() - x)));
+        next.extend(table.iter().map(|c| *c * x));
+        table = next;
+    }
+    table
+}
+
+fn msm<G: AffineCurve>(bases: &[G], scalars: &[G::ScalarField]) -> G::Projective {
+    let scalars = scalars.iter().map(|s| s.into_repr()).collect::<Vec<_>>();
+    VariableBaseMSM::multi_scalar_mul(bases, &scalars)
+}
+
+#[derive(Debug)]
+pub enum HyraxError {
+    IncompatibleShapes,
+    MissingRng,
+    PolynomialCommitment(ark_poly_commit::Error),
+}
+
+impl fmt::Display for HyraxError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::IncompatibleShapes => {
+                write!(f, "all polynomials/commitments in a batch must share num_vars")
+            }
+            Self::MissingRng => write!(f, "Hyrax commitments are hiding and need an rng"),
+            Self::PolynomialCommitment(e) => write!(f, "{:?}", e),
+        }
+    }
+}
+
+impl ark_std::error::Error for HyraxError {}
+
+impl From<ark_poly_commit::Error> for HyraxError {
+    fn from(e: ark_poly_commit::Error) -> Self {
+        Self::PolynomialCommitment(e)
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct UniversalParams<G: AffineCurve> {
+    pub generators: Vec<G>,
+    pub h: G,
+    pub max_num_vars: usize,
+}
+
+impl<G: AffineCurve> PCUniversalParams for UniversalParams<G> {
+    fn max_degree(&self) -> usize {
+        self.max_num_vars
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct CommitterKey<G: AffineCurve> {
+    pub generators: Vec<G>,
+    pub h: G,
+    pub max_num_vars: usize,
+}
+
+impl<G: AffineCurve> PCCommitterKey for CommitterKey<G> {
+    fn max_degree(&self) -> usize {
+        self.max_num_vars
+    }
+    fn supported_degree(&self) -> usize {
+        self.max_num_vars
+    }
+}
+
+pub type VerifierKey<G> = CommitterKey<G>;
+
+impl<G: AffineCurve> PCVerifierKey for VerifierKey<G> {
+    fn max_degree(&self) -> usize {
+        self.max_num_vars
+    }
+    fn supported_degree(&self) -> usize {
+        self.max_num_vars
+    }
+}
+
+impl<G: AffineCurve> ark_ff::ToBytes for VerifierKey<G> {
+    fn write<W: Write>(&self, mut writer: W) -> ark_std::io::Result<()> {
+        for generator in &self.generators {
+            generator.write(&mut writer)?;
+        }
+        self.h.write(&mut writer)
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct PreparedVerifierKey<G: AffineCurve>(pub VerifierKey<G>);
+
+impl<G: AffineCurve> PCPreparedVerifierKey<VerifierKey<G>> for PreparedVerifierKey<G> {
+    fn prepare(vk: &VerifierKey<G>) -> Self {
+        Self(vk.clone())
+    }
+}
+
+#[derive(Clone, Debug, Default, CanonicalSerialize, CanonicalDeserialize)]
+pub struct Commitment<G: AffineCurve> {
+    pub row_commitments: Vec<G>,
+}
+
+impl<G: AffineCurve> ark_ff::ToBytes for Commitment<G> {
+    fn write<W: Write>(&self, mut writer: W) -> ark_std::io::Result<()> {
+        for row_commitment in &self.row_commitments {
+            row_commitment.write(&mut writer)?;
+        }
+        Ok(())
+    }
+}
+
+impl<G: AffineCurve> PCCommitment for Commitment<G> {
+    fn empty() -> Self {
+        Self {
+            row_commitments: Vec::new(),
+        }
+    }
+    fn has_degree_bound(&self) -> bool {
+        false
+    }
+    fn size_in_bytes(&self) -> usize {
+        self.row_commitments.len() * G::zero().serialized_size()
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct PreparedCommitment<G: AffineCurve>(pub Commitment<G>);
+
+impl<G: AffineCurve> PCPreparedCommitment<Commitment<G>> for PreparedCommitment<G> {
+    fn prepare(comm: &Commitment<G>) -> Self {
+        Self(comm.clone())
+    }
+}
+
+#[derive(Clone, Debug, Default)]
+pub struct Randomness<F> {
+    pub row_randomness: Vec<F>,
+}
+
+impl<F: PrimeField> PCRandomness for Randomness<F> {
+    fn empty() -> Self {
+        Self {
+            row_randomness: Vec::new(),
+        }
+    }
+    fn rand<R: RngCore>(num_queries: usize, _has_degree_bound: bool, rng: &mut R) -> Self {
+        Self {
+            row_randomness: (0..num_queries).map(|_| F::rand(rng)).collect(),
+        }
+    }
+}
+
+#[derive(Clone, Debug, CanonicalSerialize, CanonicalDeserialize)]
+pub struct Proof<F> {
+    pub y: Vec<F>,
+    pub rho: F,
+}
+
+impl<F: PrimeField> PCProof for Proof<F> {
+    fn size_in_bytes(&self) -> usize {
+        self.serialized_size()
+    }
+}
+
+impl<G: AffineCurve> PolynomialCommitment<G::ScalarField, MultilinearExtension<G::ScalarField>>
+    for HyraxPC<G>
+{
+    type UniversalParams = UniversalParams<G>;
+    type CommitterKey = CommitterKey<G>;
+    type VerifierKey = VerifierKey<G>;
+    type PreparedVerifierKey = PreparedVerifierKey<G>;
+    type Commitment = Commitment<G>;
+    type PreparedCommitment = PreparedCommitment<G>;
+    type Randomness = Randomness<G::ScalarField>;
+    type Proof = Proof<G::ScalarField>;
+    type BatchProof = Vec<Proof<G::ScalarField>>;
+    type Error = HyraxError;
+
+    fn setup<R: RngCore>(
+        max_degree: usize,
+        _num_vars: Option<usize>,
+        rng: &mut R,
+    ) -> Result<Self::UniversalParams, Self::Error> {
+        let (_, max_cols) = split_num_vars(max_degree);
+        let generators = (0..max_cols).map(|_| G::Projective::rand(rng).into_affine()).collect();
+        let h = G::Projective::rand(rng).into_affine();
+        Ok(UniversalParams {
+            generators,
+            h,
+            max_num_vars: max_degree,
+        })
+    }
+
+    fn trim(
+        pp: &Self::UniversalParams,
+        supported_degree: usize,
+        _supported_hiding_bound: usize,
+        _enforced_degree_bounds: Option<&[usize]>,
+    ) -> Result<(Self::CommitterKey, Self::VerifierKey), Self::Error> {
+        let ck = CommitterKey {
+            generators: pp.generators.clone(),
+            h: pp.h,
+            max_num_vars: supported_degree,
+        };
+        Ok((ck.clone(), ck))
+    }
+
+    fn commit<'a>(
+        ck: &Self::CommitterKey,
+        polynomials: impl IntoIterator<
+            Item = &'a LabeledPolynomial<G::ScalarField, MultilinearExtension<G::ScalarField>>,
+        >,
+        rng: Option<&mut dyn RngCore>,
+    ) -> Result<
+        (
+            Vec<LabeledCommitment<Self::Commitment>>,
+            Vec<Self::Randomness>,
+        ),
+        Self::Error,
+    >
+    where
+        MultilinearExtension<G::ScalarField>: 'a,
+    {
+        let mut rng = rng.ok_or(HyraxError::MissingRng)?;
+        let mut labeled_commitments = Vec::new();
+        let mut randomnesses = Vec::new();
+
+        for labeled_poly in polynomials {
+            let poly = labeled_poly.polynomial();
+            let (_, cols) = split_num_vars(poly.num_vars);
+            let generators = &ck.generators[..cols];
+
+            let mut row_commitments = Vec::new();
+            let mut row_randomness = Vec::new();
+            for row in poly.evaluations.chunks(cols) {
+                let r = G::ScalarField::rand(&mut rng);
+                let commitment = msm(generators, row) + ck.h.mul(r.into_repr());
+                row_commitments.push(commitment.into_affine());
+                row_randomness.push(r);
+            }
+
+            labeled_commitments.push(LabeledCommitment::new(
+                labeled_poly.label().clone(),
+                Commitment { row_commitments },
+                None,
+            ));
+            randomnesses.push(Randomness { row_randomness });
+        }
+
+        Ok((labeled_commitments, randomnesses))
+    }
+
+    fn open<'a>(
+        _ck: &Self::CommitterKey,
+        labeled_polynomials: impl IntoIterator<
+            Item = &'a LabeledPolynomial<G::ScalarField, MultilinearExtension<G::ScalarField>>,
+        >,
+        _commitments: impl IntoIterator<Item = &'a LabeledCommitment<Self::Commitment>>,
+        point: &Vec<G::ScalarField>,
+        opening_challenge: G::ScalarField,
+        rands: impl IntoIterator<Item = &'a Self::Randomness>,
+        _rng: Option<&mut dyn RngCore>,
+    ) -> Result<Self::Proof, Self::Error>
+    where
+        Self::Randomness: 'a,
+        Self::Commitment: 'a,
+        MultilinearExtension<G::ScalarField>: 'a,
+    {
+        let num_vars = point.len();
+        let (_, cols) = split_num_vars(num_vars);
+        let col_vars = num_vars / 2;
+        let row_tensor = eq_tensor(&point[col_vars..]);
+
+        let mut y = vec![G::ScalarField::zero(); cols];
+        let mut rho = G::ScalarField::zero();
+        let mut challenge_pow = G::ScalarField::one();
+
+        for (labeled_poly, randomness) in labeled_polynomials.into_iter().zip(rands) {
+            let poly = labeled_poly.polynomial();
+            if poly.num_vars != num_vars {
+                return Err(HyraxError::IncompatibleShapes);
+            }
+
+            let mut poly_y = vec![G::ScalarField::zero(); cols];
+            for (row_idx, row) in poly.evaluations.chunks(cols).enumerate() {
+                for (j, value) in row.iter().enumerate() {
+                    poly_y[j] += row_tensor[row_idx] * value;
+                }
+            }
+            let poly_rho: G::ScalarField = row_tensor
+                .iter()
+                .zip(randomness.row_randomness.iter())
+                .map(|(l, r)| *l * r)
+                .sum();
+
+            for (y_j, poly_y_j) in y.iter_mut().zip(poly_y.iter()) {
+                *y_j += challenge_pow * poly_y_j;
+            }
+            rho += challenge_pow * poly_rho;
+            challenge_pow *= opening_challenge;
+        }
+
+        Ok(Proof { y, rho })
+    }
+
+    fn check<'a>(
+        vk: &Self::VerifierKey,
+        commitments: impl IntoIterator<Item = &'a LabeledCommitment<Self::Commitment>>,
+        point: &Vec<G::ScalarField>,
+        values: impl IntoIterator<Item = G::ScalarField>,
+        proof: &Self::Proof,
+        opening_challenge: G::ScalarField,
+        _rng: Option<&mut dyn RngCore>,
+    ) -> Result<bool, Self::Error>
+    where
+        Self::Commitment: 'a,
+    {
+        let num_vars = point.len();
+        let (_, cols) = split_num_vars(num_vars);
+        let col_vars = num_vars / 2;
+        let row_tensor = eq_tensor(&point[col_vars..]);
+        let col_tensor = eq_tensor(&point[..col_vars]);
+
+        let mut combined_commitment = G::Projective::zero();
+        let mut eval_rhs = G::ScalarField::zero();
+        let mut challenge_pow = G::ScalarField::one();
+
+        for (labeled_commitment, value) in commitments.into_iter().zip(values) {
+            let commitment = labeled_commitment.commitment();
+            if commitment.row_commitments.len() != row_tensor.len() {
+                return Err(HyraxError::IncompatibleShapes);
+            }
+            let combined_row = msm(&commitment.row_commitments, &row_tensor);
+            combined_commitment += combined_row.mul(challenge_pow.into_repr());
+            eval_rhs += challenge_pow * value;
+            challenge_pow *= opening_challenge;
+        }
+
+        let opened_commitment = msm(&vk.generators[..cols], &proof.y) + vk.h.mul(proof.rho.into_repr());
+        if combined_commitment.into_affine() != opened_commitment.into_affine() {
+            return Ok(false);
+        }
+
+        let eval_lhs: G::ScalarField = proof
+            .y
+            .iter()
+            .zip(col_tensor.iter())
+            .map(|(y, r)| *y * r)
+            .sum();
+
+        Ok(eval_lhs == eval_rhs)
+    }
+}
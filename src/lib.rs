@@ -1,7 +1,6 @@
 use ark_ec::PairingEngine;
 use ark_ff::to_bytes;
-use ark_marlin::rng::FiatShamirRng;
-use ark_poly::{univariate::DensePolynomial, Polynomial};
+use ark_poly::Polynomial;
 use ark_poly_commit::{LabeledCommitment, LabeledPolynomial, PolynomialCommitment};
 use ark_serialize::{CanonicalDeserialize, CanonicalSerialize, SerializationError};
 use ark_std::{
@@ -12,13 +11,30 @@ use ark_std::{
 };
 use digest::Digest;
 use error::from_pc_error;
+use point::SamplePoint;
 use rand::thread_rng;
+use transcript::Transcript;
 
 mod error;
+mod hyrax;
+mod multilinear;
+mod point;
+mod transcript;
 pub use error::Error;
+pub use hyrax::HyraxPC;
+pub use multilinear::MultilinearExtension;
+pub use point::SamplePoint;
+
+/// Domain separator for the transcript, bumped whenever the absorption order below changes.
+const DOMAIN_SEPARATOR: &[u8] = b"commitment-equivalence-v1";
 
 /// A proof system that attests to the fact that the same polynomial was committed to
-/// under two different polynomial commitment scheme
+/// under two different polynomial commitment schemes.
+///
+/// `P` is generic over `ark_poly::Polynomial`, so both univariate schemes (e.g. KZG, IPA)
+/// and multilinear schemes can be bridged, as long as `P::Point` implements [`SamplePoint`].
+/// [`HyraxPC`] and [`MultilinearExtension`] are a transparent, Pedersen-based multilinear
+/// `PC1`/`PC2` for exactly this purpose (see `examples/hyrax_equivalence.rs`).
 pub struct PolyCommitEquivalence<
     D: Digest,
     E: PairingEngine,
@@ -33,128 +49,371 @@ pub struct PolyCommitEquivalence<
     _pc2: PhantomData<PC2>,
 }
 
-/// Proof for the PolyCommitEquivalence protocol
-#[derive(Clone, Copy, CanonicalSerialize, CanonicalDeserialize)]
+/// Proof for the PolyCommitEquivalence protocol.
+///
+/// Holds one evaluation and one opening per scheme for every challenge point drawn, so that
+/// [`PolyCommitEquivalence::prove`] can be asked for a `security_level` of `k > 1`: since two
+/// differing polynomials can only agree at a random point with probability `d / |F|`, checking
+/// `k` independently-drawn points drives the cheating probability down to `(d / |F|)^k`.
+#[derive(Clone, CanonicalSerialize, CanonicalDeserialize)]
 pub struct Proof<
     E: PairingEngine,
     P: Polynomial<E::Fr>,
     PC1: PolynomialCommitment<E::Fr, P>,
     PC2: PolynomialCommitment<E::Fr, P>,
 > {
-    pub eval: E::Fr,
+    pub evals: Vec<E::Fr>,
+    pub openings: (Vec<PC1::Proof>, Vec<PC2::Proof>),
+}
+
+/// Proof that a *batch* of polynomials was committed to under two different polynomial
+/// commitment schemes, produced by [`PolyCommitEquivalence::prove_batch`].
+///
+/// All polynomials in the batch are opened at the same Fiat-Shamir challenge point, combined
+/// under a single batching scalar, so the cost of the two openings is paid once no matter how
+/// many polynomials are being bridged.
+#[derive(Clone, CanonicalSerialize, CanonicalDeserialize)]
+pub struct BatchProof<
+    E: PairingEngine,
+    P: Polynomial<E::Fr>,
+    PC1: PolynomialCommitment<E::Fr, P>,
+    PC2: PolynomialCommitment<E::Fr, P>,
+> {
+    pub evals: Vec<E::Fr>,
     pub openings: (PC1::Proof, PC2::Proof),
 }
 
-impl<D, E, PC1, PC2> PolyCommitEquivalence<D, E, DensePolynomial<E::Fr>, PC1, PC2>
+impl<D, E, P, PC1, PC2> PolyCommitEquivalence<D, E, P, PC1, PC2>
 where
     D: Digest,
     E: PairingEngine,
-    PC1: PolynomialCommitment<E::Fr, DensePolynomial<E::Fr>>,
-    PC2: PolynomialCommitment<E::Fr, DensePolynomial<E::Fr>>,
+    P: Polynomial<E::Fr>,
+    P::Point: SamplePoint<E::Fr>,
+    PC1: PolynomialCommitment<E::Fr, P>,
+    PC2: PolynomialCommitment<E::Fr, P>,
 {
+    /// Build the transcript shared by `prove`/`verify`/`prove_batch`/`verify_batch`: absorb the
+    /// protocol's domain separator, both verifier keys, then for every commitment pair both
+    /// declared degree bounds and both labeled commitments, in that fixed order. This binds
+    /// the challenge point and opening challenge to the verifier keys and commitments being
+    /// bridged, so the transcript is unambiguous across parameter sets and across every item
+    /// in a batch.
+    fn start_transcript<'a>(
+        verifier_keys: (&PC1::VerifierKey, &PC2::VerifierKey),
+        commitments: impl IntoIterator<
+            Item = (
+                &'a LabeledCommitment<PC1::Commitment>,
+                &'a LabeledCommitment<PC2::Commitment>,
+            ),
+        >,
+    ) -> Result<Transcript<D>, Error>
+    where
+        PC1::Commitment: 'a,
+        PC2::Commitment: 'a,
+    {
+        let mut transcript = Transcript::<D>::new(DOMAIN_SEPARATOR);
+        transcript.absorb(&to_bytes!(verifier_keys.0, verifier_keys.1)?);
+        for (c1, c2) in commitments {
+            transcript.absorb(&c1.degree_bound().unwrap_or(0).to_le_bytes());
+            transcript.absorb(&c2.degree_bound().unwrap_or(0).to_le_bytes());
+            transcript.absorb(&to_bytes!(c1.commitment(), c2.commitment())?);
+        }
+        Ok(transcript)
+    }
+
+    /// Prove that `polynomial` was committed to under both `PC1` and `PC2`, checking
+    /// `security_level` independently-drawn challenge points. A cheating prover who committed
+    /// to two differing polynomials can only pass at any one point with probability `d / |F|`,
+    /// so drawing `k = security_level` points drives that probability down to `(d / |F|)^k`;
+    /// `security_level = 1` recovers the original single-point argument.
+    ///
+    /// `num_vars` is the shape of `P::Point`: the number of independent field elements a
+    /// challenge point is made of (e.g. the number of variables for a multilinear polynomial).
+    /// It is unused (and may be `0`) for univariate `P`, whose `Point` is a single field element.
+    #[allow(clippy::too_many_arguments)]
     pub fn prove(
         commit_keys: (&PC1::CommitterKey, &PC2::CommitterKey),
-        polynomial: &LabeledPolynomial<E::Fr, DensePolynomial<E::Fr>>,
+        verifier_keys: (&PC1::VerifierKey, &PC2::VerifierKey),
+        polynomial: &LabeledPolynomial<E::Fr, P>,
         commitments: (
             &LabeledCommitment<PC1::Commitment>,
             &LabeledCommitment<PC2::Commitment>,
         ),
         randomnesses: (&PC1::Randomness, &PC2::Randomness),
-    ) -> Result<Proof<E, DensePolynomial<E::Fr>, PC1, PC2>, Error> {
+        num_vars: usize,
+        security_level: usize,
+    ) -> Result<Proof<E, P, PC1, PC2>, Error> {
+        let rng = &mut thread_rng();
+
+        let mut transcript =
+            Self::start_transcript(verifier_keys, iter::once((commitments.0, commitments.1)))?;
+
+        let mut evals = Vec::with_capacity(security_level);
+        let mut pc1_openings = Vec::with_capacity(security_level);
+        let mut pc2_openings = Vec::with_capacity(security_level);
+
+        for i in 0..security_level {
+            // Derive the i-th challenge point, domain-separated by its index so that no two
+            // points in the same proof are ever drawn from the same absorbed state. For a
+            // multilinear polynomial over `ν` variables this draws `ν` independent field
+            // elements into a point in `F^ν`; for a univariate polynomial it draws one.
+            transcript.absorb(format!("challenge-point-{}", i).as_bytes());
+            let challenge_point = P::Point::sample(&mut transcript, num_vars);
+
+            // Compute the evaluation and bind it into the transcript before drawing the
+            // opening challenge, so the opening challenge cannot be chosen independently of
+            // what is proved
+            let evaluation = polynomial.evaluate(&challenge_point);
+            transcript.absorb(&to_bytes!(evaluation)?);
+            transcript.absorb(format!("opening-challenge-{}", i).as_bytes());
+            let opening_challenge = E::Fr::rand(&mut transcript);
+
+            // Open both commitments at the challenge point
+            let pc1_opening = PC1::open(
+                commit_keys.0,
+                iter::once(polynomial),
+                iter::once(commitments.0),
+                &challenge_point,
+                opening_challenge,
+                iter::once(randomnesses.0),
+                Some(rng),
+            )
+            .map_err(from_pc_error::<E::Fr, P, PC1>)?;
+
+            let pc2_opening = PC2::open(
+                commit_keys.1,
+                iter::once(polynomial),
+                iter::once(commitments.1),
+                &challenge_point,
+                opening_challenge,
+                iter::once(randomnesses.1),
+                Some(rng),
+            )
+            .map_err(from_pc_error::<E::Fr, P, PC2>)?;
+
+            evals.push(evaluation);
+            pc1_openings.push(pc1_opening);
+            pc2_openings.push(pc2_opening);
+        }
+
+        Ok(Proof {
+            evals,
+            openings: (pc1_openings, pc2_openings),
+        })
+    }
+
+    /// Verify a [`Proof`] produced by [`Self::prove`] at the caller's required `security_level`,
+    /// for the same `num_vars` shape the proof was created with. `security_level` is the
+    /// caller's own requirement, not read off the proof, so a prover cannot shrink (or empty
+    /// out) the number of points checked by under-filling the proof.
+    pub fn verify(
+        verifier_keys: (&PC1::VerifierKey, &PC2::VerifierKey),
+        commitments: (
+            &LabeledCommitment<PC1::Commitment>,
+            &LabeledCommitment<PC2::Commitment>,
+        ),
+        proof: Proof<E, P, PC1, PC2>,
+        num_vars: usize,
+        security_level: usize,
+    ) -> Result<(), Error> {
+        if proof.evals.is_empty()
+            || proof.evals.len() < security_level
+            || proof.evals.len() != proof.openings.0.len()
+            || proof.evals.len() != proof.openings.1.len()
+        {
+            return Err(Error::BatchSizeMismatch);
+        }
+
+        let rng = &mut thread_rng();
+
+        let mut transcript =
+            Self::start_transcript(verifier_keys, iter::once((commitments.0, commitments.1)))?;
+
+        for (i, ((eval, pc1_opening), pc2_opening)) in proof
+            .evals
+            .iter()
+            .zip(proof.openings.0.iter())
+            .zip(proof.openings.1.iter())
+            .enumerate()
+        {
+            transcript.absorb(format!("challenge-point-{}", i).as_bytes());
+            let challenge_point = P::Point::sample(&mut transcript, num_vars);
+
+            transcript.absorb(&to_bytes!(eval)?);
+            transcript.absorb(format!("opening-challenge-{}", i).as_bytes());
+            let opening_challenge = E::Fr::rand(&mut transcript);
+
+            let kzg_check = PC1::check(
+                verifier_keys.0,
+                iter::once(commitments.0),
+                &challenge_point,
+                iter::once(*eval),
+                pc1_opening,
+                opening_challenge,
+                Some(rng),
+            );
+            match kzg_check {
+                Ok(true) => (),
+                Ok(false) => return Err(Error::KZGFailed),
+                Err(e) => return Err(from_pc_error::<E::Fr, P, PC1>(e)),
+            }
+
+            let ipa_check = PC2::check(
+                verifier_keys.1,
+                iter::once(commitments.1),
+                &challenge_point,
+                iter::once(*eval),
+                pc2_opening,
+                opening_challenge,
+                Some(rng),
+            );
+            match ipa_check {
+                Ok(true) => (),
+                Ok(false) => return Err(Error::IPAFailed),
+                Err(e) => return Err(from_pc_error::<E::Fr, P, PC2>(e)),
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Prove that every polynomial in `polynomials` was committed to under both `PC1` and
+    /// `PC2`, at the corresponding index in `commitments` and `randomnesses`.
+    ///
+    /// Both verifier keys and every commitment pair (from both schemes) are absorbed into a
+    /// single transcript to derive one shared challenge point `z`; the claimed evaluations are
+    /// then absorbed to derive one batching scalar `ρ`. Each scheme's `open` is called once,
+    /// using its native support for batching several polynomials into a single proof via
+    /// powers of `ρ`, so the cost of bridging is paid once regardless of batch size. `num_vars`
+    /// is the shape of `P::Point`, as in [`Self::prove`].
+    #[allow(clippy::too_many_arguments)]
+    pub fn prove_batch(
+        commit_keys: (&PC1::CommitterKey, &PC2::CommitterKey),
+        verifier_keys: (&PC1::VerifierKey, &PC2::VerifierKey),
+        polynomials: &[LabeledPolynomial<E::Fr, P>],
+        commitments: (
+            &[LabeledCommitment<PC1::Commitment>],
+            &[LabeledCommitment<PC2::Commitment>],
+        ),
+        randomnesses: (&[PC1::Randomness], &[PC2::Randomness]),
+        num_vars: usize,
+    ) -> Result<BatchProof<E, P, PC1, PC2>, Error> {
+        if polynomials.len() != commitments.0.len()
+            || polynomials.len() != commitments.1.len()
+            || polynomials.len() != randomnesses.0.len()
+            || polynomials.len() != randomnesses.1.len()
+        {
+            return Err(Error::BatchSizeMismatch);
+        }
+
         let rng = &mut thread_rng();
 
-        // Derive a challenge point
-        let mut fs_rng = FiatShamirRng::<D>::from_seed(b"");
-        fs_rng.absorb(&to_bytes!(
-            commitments.0.commitment(),
-            commitments.1.commitment()
-        )?);
+        // Derive a challenge point and a batching scalar from the verifier keys and every
+        // commitment in the batch
+        let mut transcript =
+            Self::start_transcript(verifier_keys, commitments.0.iter().zip(commitments.1.iter()))?;
+
+        transcript.absorb(b"challenge-point");
+        let challenge_point = P::Point::sample(&mut transcript, num_vars);
 
-        let challenge_point = E::Fr::rand(&mut fs_rng);
-        let opening_challenge = E::Fr::rand(&mut fs_rng);
+        // Evaluate every polynomial at the shared challenge point
+        let evals: Vec<E::Fr> = polynomials
+            .iter()
+            .map(|polynomial| polynomial.evaluate(&challenge_point))
+            .collect();
 
-        // Compute the evaluation
-        let evaluation = polynomial.evaluate(&challenge_point);
+        // Bind the claimed evaluations before drawing the batching scalar
+        transcript.absorb(&to_bytes!(evals)?);
+        transcript.absorb(b"batching-scalar");
+        let batching_scalar = E::Fr::rand(&mut transcript);
 
-        // Open both commitments at the challenge point
+        // Open the random linear combination of all polynomials at the challenge point,
+        // under both schemes, using `batching_scalar` as the native batching challenge
         let pc1_opening = PC1::open(
             commit_keys.0,
-            iter::once(polynomial),
-            iter::once(commitments.0),
+            polynomials.iter(),
+            commitments.0.iter(),
             &challenge_point,
-            opening_challenge,
-            iter::once(randomnesses.0),
+            batching_scalar,
+            randomnesses.0.iter(),
             Some(rng),
         )
-        .map_err(from_pc_error::<E::Fr, PC1>)?;
+        .map_err(from_pc_error::<E::Fr, P, PC1>)?;
 
         let pc2_opening = PC2::open(
             commit_keys.1,
-            iter::once(polynomial),
-            iter::once(commitments.1),
+            polynomials.iter(),
+            commitments.1.iter(),
             &challenge_point,
-            opening_challenge,
-            iter::once(randomnesses.1),
+            batching_scalar,
+            randomnesses.1.iter(),
             Some(rng),
         )
-        .map_err(from_pc_error::<E::Fr, PC2>)?;
+        .map_err(from_pc_error::<E::Fr, P, PC2>)?;
 
-        // Return openings
-        let proof = Proof {
-            eval: evaluation,
+        Ok(BatchProof {
+            evals,
             openings: (pc1_opening, pc2_opening),
-        };
-        Ok(proof)
+        })
     }
 
-    pub fn verify(
+    /// Verify a [`BatchProof`] produced by [`Self::prove_batch`], for the same `num_vars`
+    /// shape it was created with.
+    pub fn verify_batch(
         verifier_keys: (&PC1::VerifierKey, &PC2::VerifierKey),
         commitments: (
-            &LabeledCommitment<PC1::Commitment>,
-            &LabeledCommitment<PC2::Commitment>,
+            &[LabeledCommitment<PC1::Commitment>],
+            &[LabeledCommitment<PC2::Commitment>],
         ),
-        proof: Proof<E, DensePolynomial<E::Fr>, PC1, PC2>,
+        proof: BatchProof<E, P, PC1, PC2>,
+        num_vars: usize,
     ) -> Result<(), Error> {
+        if commitments.0.len() != commitments.1.len() || commitments.0.len() != proof.evals.len()
+        {
+            return Err(Error::BatchSizeMismatch);
+        }
+
         let rng = &mut thread_rng();
 
-        // Derive a challenge point
-        let mut fs_rng = FiatShamirRng::<D>::from_seed(b"");
-        fs_rng.absorb(&to_bytes!(
-            commitments.0.commitment(),
-            commitments.1.commitment()
-        )?);
-        let challenge_point = E::Fr::rand(&mut fs_rng);
-        let opening_challenge = E::Fr::rand(&mut fs_rng);
+        // Recompute the challenge point and batching scalar
+        let mut transcript =
+            Self::start_transcript(verifier_keys, commitments.0.iter().zip(commitments.1.iter()))?;
+
+        transcript.absorb(b"challenge-point");
+        let challenge_point = P::Point::sample(&mut transcript, num_vars);
+
+        transcript.absorb(&to_bytes!(proof.evals)?);
+        transcript.absorb(b"batching-scalar");
+        let batching_scalar = E::Fr::rand(&mut transcript);
 
-        // Check both openings
         let kzg_check = PC1::check(
             verifier_keys.0,
-            iter::once(commitments.0),
+            commitments.0.iter(),
             &challenge_point,
-            iter::once(proof.eval),
+            proof.evals.iter().copied(),
             &proof.openings.0,
-            opening_challenge,
+            batching_scalar,
             Some(rng),
         );
         match kzg_check {
             Ok(true) => (),
             Ok(false) => return Err(Error::KZGFailed),
-            Err(e) => return Err(from_pc_error::<E::Fr, PC1>(e)),
+            Err(e) => return Err(from_pc_error::<E::Fr, P, PC1>(e)),
         }
 
         let ipa_check = PC2::check(
             verifier_keys.1,
-            iter::once(commitments.1),
+            commitments.1.iter(),
             &challenge_point,
-            iter::once(proof.eval),
+            proof.evals.iter().copied(),
             &proof.openings.1,
-            opening_challenge,
+            batching_scalar,
             Some(rng),
         );
         match ipa_check {
             Ok(true) => (),
             Ok(false) => return Err(Error::IPAFailed),
-            Err(e) => return Err(from_pc_error::<E::Fr, PC2>(e)),
+            Err(e) => return Err(from_pc_error::<E::Fr, P, PC2>(e)),
         }
 
         Ok(())
@@ -165,6 +424,7 @@ where
 mod tests {
     use ark_bn254::{Bn254, Fr};
     use ark_ec::PairingEngine;
+    use ark_ff::UniformRand;
     use ark_poly::{univariate::DensePolynomial, UVPolynomial};
     use ark_poly_commit::{
         ipa_pc::InnerProductArgPC, sonic_pc::SonicKZG10, LabeledPolynomial, PolynomialCommitment,
@@ -173,12 +433,16 @@ mod tests {
     use rand::thread_rng;
     use std::iter;
 
-    use crate::PolyCommitEquivalence;
+    use crate::{HyraxPC, MultilinearExtension, PolyCommitEquivalence};
 
     type KZG = SonicKZG10<Bn254, DensePolynomial<Fr>>;
     type IPA = InnerProductArgPC<<Bn254 as PairingEngine>::G1Affine, Blake2s, DensePolynomial<Fr>>;
     type PCEquivalence = PolyCommitEquivalence<Blake2s, Bn254, DensePolynomial<Fr>, KZG, IPA>;
 
+    type Hyrax = HyraxPC<<Bn254 as PairingEngine>::G1Affine>;
+    type HyraxEquivalence =
+        PolyCommitEquivalence<Blake2s, Bn254, MultilinearExtension<Fr>, Hyrax, Hyrax>;
+
     #[test]
     fn ipa_kzg_equivalence_accept() {
         let rng = &mut thread_rng();
@@ -205,14 +469,105 @@ mod tests {
         // Proof of equivalence
         let proof = PCEquivalence::prove(
             (&kzg_ck, &ipa_ck),
+            (&kzg_vk, &ipa_vk),
             &poly,
             (&kzg_commit[0], &ipa_commit[0]),
             (&kzg_rand[0], &ipa_rand[0]),
+            0,
+            3,
         )
         .unwrap();
 
         // Verify proof
-        PCEquivalence::verify((&kzg_vk, &ipa_vk), (&kzg_commit[0], &ipa_commit[0]), proof).unwrap();
+        PCEquivalence::verify((&kzg_vk, &ipa_vk), (&kzg_commit[0], &ipa_commit[0]), proof, 0, 3)
+            .unwrap();
+    }
+
+    #[test]
+    fn ipa_kzg_equivalence_rejects_empty_proof() {
+        let rng = &mut thread_rng();
+        let max_degree = 20;
+        let max_hiding = 1;
+
+        // Random polynomial
+        let poly: DensePolynomial<Fr> = DensePolynomial::rand(max_degree - 1, rng);
+        let poly = LabeledPolynomial::new(String::from("poly"), poly, Some(max_degree), Some(1));
+
+        let other_poly: DensePolynomial<Fr> = DensePolynomial::rand(max_degree - 1, rng);
+        let other_poly =
+            LabeledPolynomial::new(String::from("poly"), other_poly, Some(max_degree), Some(1));
+
+        // Setup commitment schemes
+        let kzg_pp = KZG::setup(max_degree, None, rng).unwrap();
+        let (_, kzg_vk) = KZG::trim(&kzg_pp, max_degree, max_hiding, Some(&[max_degree])).unwrap();
+
+        let ipa_pp = IPA::setup(max_degree, None, rng).unwrap();
+        let (ipa_ck, ipa_vk) =
+            IPA::trim(&ipa_pp, max_degree, max_hiding, Some(&[max_degree])).unwrap();
+
+        // Commit to different polynomials with both schemes: nothing stops a malicious
+        // prover from hand-constructing a proof with zero points for commitments like this
+        let kzg_pp2 = KZG::setup(max_degree, None, rng).unwrap();
+        let (kzg_ck, _) = KZG::trim(&kzg_pp2, max_degree, max_hiding, Some(&[max_degree])).unwrap();
+        let (kzg_commit, _) = KZG::commit(&kzg_ck, iter::once(&poly), Some(rng)).unwrap();
+        let (ipa_commit, _) = IPA::commit(&ipa_ck, iter::once(&other_poly), Some(rng)).unwrap();
+
+        let empty_proof = super::Proof {
+            evals: vec![],
+            openings: (vec![], vec![]),
+        };
+
+        // An empty proof must never verify, no matter what security level the caller asked for
+        let check = PCEquivalence::verify(
+            (&kzg_vk, &ipa_vk),
+            (&kzg_commit[0], &ipa_commit[0]),
+            empty_proof,
+            0,
+            3,
+        );
+
+        assert!(check.is_err());
+    }
+
+    #[test]
+    fn hyrax_equivalence_accept() {
+        let rng = &mut thread_rng();
+        let num_vars = 4;
+        let max_hiding = 1;
+
+        // Random multilinear polynomial, bridged between two independent Hyrax parameter sets
+        let evaluations: Vec<Fr> = (0..1 << num_vars).map(|_| Fr::rand(rng)).collect();
+        let poly = MultilinearExtension::from_evaluations(num_vars, evaluations);
+        let poly = LabeledPolynomial::new(String::from("poly"), poly, None, None);
+
+        let pp_1 = Hyrax::setup(num_vars, None, rng).unwrap();
+        let (ck_1, vk_1) = Hyrax::trim(&pp_1, num_vars, max_hiding, None).unwrap();
+
+        let pp_2 = Hyrax::setup(num_vars, None, rng).unwrap();
+        let (ck_2, vk_2) = Hyrax::trim(&pp_2, num_vars, max_hiding, None).unwrap();
+
+        let (commit_1, rand_1) = Hyrax::commit(&ck_1, iter::once(&poly), Some(rng)).unwrap();
+        let (commit_2, rand_2) = Hyrax::commit(&ck_2, iter::once(&poly), Some(rng)).unwrap();
+
+        let proof = HyraxEquivalence::prove(
+            (&ck_1, &ck_2),
+            (&vk_1, &vk_2),
+            &poly,
+            (&commit_1[0], &commit_2[0]),
+            (&rand_1[0], &rand_2[0]),
+            num_vars,
+            3,
+        )
+        .unwrap();
+
+        HyraxEquivalence::verify(
+            (&vk_1, &vk_2),
+            (&commit_1[0], &commit_2[0]),
+            proof,
+            num_vars,
+            3,
+        )
+        .unwrap();
     }
 
     #[test]
@@ -246,16 +601,171 @@ mod tests {
         // Proof of equivalence
         let proof = PCEquivalence::prove(
             (&kzg_ck, &ipa_ck),
+            (&kzg_vk, &ipa_vk),
             &poly,
             (&kzg_commit[0], &ipa_commit[0]),
             (&kzg_rand[0], &ipa_rand[0]),
+            0,
+            3,
         )
         .unwrap();
 
         // Verify proof
-        let check =
-            PCEquivalence::verify((&kzg_vk, &ipa_vk), (&kzg_commit[0], &ipa_commit[0]), proof);
+        let check = PCEquivalence::verify(
+            (&kzg_vk, &ipa_vk),
+            (&kzg_commit[0], &ipa_commit[0]),
+            proof,
+            0,
+            3,
+        );
 
         assert!(check.is_err());
     }
+
+    #[test]
+    fn ipa_kzg_batch_equivalence_accept() {
+        let rng = &mut thread_rng();
+        let max_degree = 20;
+        let max_hiding = 1;
+        let batch_size = 4;
+
+        let polys: Vec<_> = (0..batch_size)
+            .map(|i| {
+                let poly: DensePolynomial<Fr> = DensePolynomial::rand(max_degree - 1, rng);
+                LabeledPolynomial::new(format!("poly-{}", i), poly, Some(max_degree), Some(1))
+            })
+            .collect();
+
+        // Setup commitment schemes
+        let kzg_pp = KZG::setup(max_degree, None, rng).unwrap();
+        let (kzg_ck, kzg_vk) =
+            KZG::trim(&kzg_pp, max_degree, max_hiding, Some(&[max_degree])).unwrap();
+
+        let ipa_pp = IPA::setup(max_degree, None, rng).unwrap();
+        let (ipa_ck, ipa_vk) =
+            IPA::trim(&ipa_pp, max_degree, max_hiding, Some(&[max_degree])).unwrap();
+
+        // Commit to the batch with both schemes
+        let (kzg_commits, kzg_rands) = KZG::commit(&kzg_ck, polys.iter(), Some(rng)).unwrap();
+        let (ipa_commits, ipa_rands) = IPA::commit(&ipa_ck, polys.iter(), Some(rng)).unwrap();
+
+        // Proof of batch equivalence
+        let proof = PCEquivalence::prove_batch(
+            (&kzg_ck, &ipa_ck),
+            (&kzg_vk, &ipa_vk),
+            &polys,
+            (&kzg_commits, &ipa_commits),
+            (&kzg_rands, &ipa_rands),
+            0,
+        )
+        .unwrap();
+
+        // Verify proof
+        PCEquivalence::verify_batch((&kzg_vk, &ipa_vk), (&kzg_commits, &ipa_commits), proof, 0)
+            .unwrap();
+    }
+
+    #[test]
+    fn ipa_kzg_batch_equivalence_reject() {
+        let rng = &mut thread_rng();
+        let max_degree = 20;
+        let max_hiding = 1;
+        let batch_size = 4;
+
+        let polys: Vec<_> = (0..batch_size)
+            .map(|i| {
+                let poly: DensePolynomial<Fr> = DensePolynomial::rand(max_degree - 1, rng);
+                LabeledPolynomial::new(format!("poly-{}", i), poly, Some(max_degree), Some(1))
+            })
+            .collect();
+
+        let other_polys: Vec<_> = (0..batch_size)
+            .map(|i| {
+                let poly: DensePolynomial<Fr> = DensePolynomial::rand(max_degree - 1, rng);
+                LabeledPolynomial::new(format!("poly-{}", i), poly, Some(max_degree), Some(1))
+            })
+            .collect();
+
+        // Setup commitment schemes
+        let kzg_pp = KZG::setup(max_degree, None, rng).unwrap();
+        let (kzg_ck, kzg_vk) =
+            KZG::trim(&kzg_pp, max_degree, max_hiding, Some(&[max_degree])).unwrap();
+
+        let ipa_pp = IPA::setup(max_degree, None, rng).unwrap();
+        let (ipa_ck, ipa_vk) =
+            IPA::trim(&ipa_pp, max_degree, max_hiding, Some(&[max_degree])).unwrap();
+
+        // Commit to different batches with both schemes
+        let (kzg_commits, kzg_rands) = KZG::commit(&kzg_ck, polys.iter(), Some(rng)).unwrap();
+        let (ipa_commits, ipa_rands) =
+            IPA::commit(&ipa_ck, other_polys.iter(), Some(rng)).unwrap();
+
+        // Proof of batch equivalence
+        let proof = PCEquivalence::prove_batch(
+            (&kzg_ck, &ipa_ck),
+            (&kzg_vk, &ipa_vk),
+            &polys,
+            (&kzg_commits, &ipa_commits),
+            (&kzg_rands, &ipa_rands),
+            0,
+        )
+        .unwrap();
+
+        // Verify proof
+        let check = PCEquivalence::verify_batch(
+            (&kzg_vk, &ipa_vk),
+            (&kzg_commits, &ipa_commits),
+            proof,
+            0,
+        );
+
+        assert!(check.is_err());
+    }
+
+    #[test]
+    fn hyrax_batch_equivalence_accept() {
+        let rng = &mut thread_rng();
+        let num_vars = 4;
+        let max_hiding = 1;
+        let batch_size = 4;
+
+        let polys: Vec<_> = (0..batch_size)
+            .map(|i| {
+                let evaluations: Vec<Fr> = (0..1 << num_vars).map(|_| Fr::rand(rng)).collect();
+                let poly = MultilinearExtension::from_evaluations(num_vars, evaluations);
+                LabeledPolynomial::new(format!("poly-{}", i), poly, None, None)
+            })
+            .collect();
+
+        let pp_1 = Hyrax::setup(num_vars, None, rng).unwrap();
+        let (ck_1, vk_1) = Hyrax::trim(&pp_1, num_vars, max_hiding, None).unwrap();
+
+        let pp_2 = Hyrax::setup(num_vars, None, rng).unwrap();
+        let (ck_2, vk_2) = Hyrax::trim(&pp_2, num_vars, max_hiding, None).unwrap();
+
+        // Commit to the batch with both parameter sets
+        let (commits_1, rands_1) = Hyrax::commit(&ck_1, polys.iter(), Some(rng)).unwrap();
+        let (commits_2, rands_2) = Hyrax::commit(&ck_2, polys.iter(), Some(rng)).unwrap();
+
+        // Hyrax commitments carry no degree bound, so the challenge point's shape comes only
+        // from the explicit `num_vars` argument; a zero-length point would panic in
+        // MultilinearExtension::evaluate's length assertion.
+        let proof = HyraxEquivalence::prove_batch(
+            (&ck_1, &ck_2),
+            (&vk_1, &vk_2),
+            &polys,
+            (&commits_1, &commits_2),
+            (&rands_1, &rands_2),
+            num_vars,
+        )
+        .unwrap();
+
+        HyraxEquivalence::verify_batch(
+            (&vk_1, &vk_2),
+            (&commits_1, &commits_2),
+            proof,
+            num_vars,
+        )
+        .unwrap();
+    }
 }
@@ -0,0 +1,105 @@
+use ark_ff::{Field, Zero};
+use ark_poly::Polynomial;
+use ark_serialize::{CanonicalDeserialize, CanonicalSerialize, SerializationError};
+use ark_std::io::{Read, Write};
+use ark_std::ops::{Add, AddAssign, Neg};
+use ark_std::vec::Vec;
+
+/// A multilinear polynomial over `num_vars` variables, represented by its evaluations over
+/// the Boolean hypercube `{0,1}^num_vars` (index `i`'s bits give the evaluation point).
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Default, CanonicalSerialize, CanonicalDeserialize)]
+pub struct MultilinearExtension<F: Field> {
+    pub num_vars: usize,
+    pub evaluations: Vec<F>,
+}
+
+impl<F: Field> MultilinearExtension<F> {
+    pub fn from_evaluations(num_vars: usize, evaluations: Vec<F>) -> Self {
+        assert_eq!(evaluations.len(), 1 << num_vars);
+        Self {
+            num_vars,
+            evaluations,
+        }
+    }
+}
+
+impl<F: Field> Polynomial<F> for MultilinearExtension<F> {
+    type Point = Vec<F>;
+
+    fn degree(&self) -> usize {
+        self.num_vars
+    }
+
+    /// Evaluate via repeated linear interpolation over each variable in turn.
+    fn evaluate(&self, point: &Vec<F>) -> F {
+        assert_eq!(point.len(), self.num_vars);
+        let mut table = self.evaluations.clone();
+        for x in point {
+            let half = table.len() / 2;
+            for i in 0..half {
+                table[i] = table[2 * i] + (table[2 * i + 1] - table[2 * i]) * x;
+            }
+            table.truncate(half);
+        }
+        table.first().copied().unwrap_or_else(F::zero)
+    }
+}
+
+impl<F: Field> Zero for MultilinearExtension<F> {
+    fn zero() -> Self {
+        Self {
+            num_vars: 0,
+            evaluations: vec![F::zero()],
+        }
+    }
+
+    fn is_zero(&self) -> bool {
+        self.evaluations.iter().all(F::is_zero)
+    }
+}
+
+impl<F: Field> Add<Self> for MultilinearExtension<F> {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        assert_eq!(self.num_vars, other.num_vars);
+        let evaluations = self
+            .evaluations
+            .iter()
+            .zip(other.evaluations.iter())
+            .map(|(a, b)| *a + *b)
+            .collect();
+        Self {
+            num_vars: self.num_vars,
+            evaluations,
+        }
+    }
+}
+
+impl<'a, F: Field> Add<&'a Self> for MultilinearExtension<F> {
+    type Output = Self;
+
+    fn add(self, other: &'a Self) -> Self {
+        self.add(other.clone())
+    }
+}
+
+impl<'a, F: Field> AddAssign<(F, &'a Self)> for MultilinearExtension<F> {
+    fn add_assign(&mut self, (scalar, other): (F, &'a Self)) {
+        assert_eq!(self.num_vars, other.num_vars);
+        for (a, b) in self.evaluations.iter_mut().zip(other.evaluations.iter()) {
+            *a += scalar * b;
+        }
+    }
+}
+
+impl<F: Field> Neg for MultilinearExtension<F> {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Self {
+            num_vars: self.num_vars,
+            evaluations: self.evaluations.iter().map(|e| -*e).collect(),
+        }
+    }
+}
@@ -0,0 +1,26 @@
+use ark_ff::{Field, UniformRand};
+use ark_std::rand::RngCore;
+
+/// A point that can be sampled from a Fiat-Shamir RNG as a sequence of field elements.
+///
+/// A univariate polynomial is evaluated at a single field element, while a multilinear
+/// polynomial over `ν` variables is evaluated at a point in `F^ν`. This trait lets
+/// [`crate::PolyCommitEquivalence`] sample `P::Point` without knowing which shape it is,
+/// by asking for `num_vars` field elements and letting the impl decide how many it needs.
+pub trait SamplePoint<F: Field>: Clone {
+    /// Draw `num_vars` independent field elements from `rng` and assemble them into a point.
+    /// Implementations for points made up of a single field element ignore `num_vars`.
+    fn sample<R: RngCore>(rng: &mut R, num_vars: usize) -> Self;
+}
+
+impl<F: Field> SamplePoint<F> for F {
+    fn sample<R: RngCore>(rng: &mut R, _num_vars: usize) -> Self {
+        F::rand(rng)
+    }
+}
+
+impl<F: Field> SamplePoint<F> for Vec<F> {
+    fn sample<R: RngCore>(rng: &mut R, num_vars: usize) -> Self {
+        (0..num_vars).map(|_| F::rand(rng)).collect()
+    }
+}
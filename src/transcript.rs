@@ -0,0 +1,66 @@
+use ark_std::rand::{Error as RandError, RngCore};
+use digest::Digest;
+use std::marker::PhantomData;
+
+/// A minimal domain-separated sponge-style Fiat-Shamir transcript built on a hash function `D`.
+/// Data must be [`absorb`](Transcript::absorb)ed before a challenge is drawn from it as an
+/// `RngCore` source, the same way the crate previously drew challenges from `FiatShamirRng`.
+pub(crate) struct Transcript<D: Digest> {
+    state: Vec<u8>,
+    _digest: PhantomData<D>,
+}
+
+impl<D: Digest> Transcript<D> {
+    /// Start a new transcript bound to `domain_separator`, so transcripts for different
+    /// protocols (or protocol versions) never collide even given identical absorbed data.
+    pub fn new(domain_separator: &'static [u8]) -> Self {
+        let mut transcript = Self {
+            state: Vec::new(),
+            _digest: PhantomData,
+        };
+        transcript.absorb(domain_separator);
+        transcript
+    }
+
+    /// Absorb bytes into the transcript state.
+    pub fn absorb(&mut self, bytes: &[u8]) {
+        let mut hasher = D::new();
+        hasher.update(&self.state);
+        hasher.update(bytes);
+        self.state = hasher.finalize().to_vec();
+    }
+}
+
+impl<D: Digest> RngCore for Transcript<D> {
+    fn next_u32(&mut self) -> u32 {
+        let mut bytes = [0u8; 4];
+        self.fill_bytes(&mut bytes);
+        u32::from_le_bytes(bytes)
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        let mut bytes = [0u8; 8];
+        self.fill_bytes(&mut bytes);
+        u64::from_le_bytes(bytes)
+    }
+
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        let mut filled = 0;
+        while filled < dest.len() {
+            let mut hasher = D::new();
+            hasher.update(&self.state);
+            hasher.update(b"squeeze");
+            let block = hasher.finalize();
+            self.state = block.to_vec();
+
+            let n = core::cmp::min(block.len(), dest.len() - filled);
+            dest[filled..filled + n].copy_from_slice(&block[..n]);
+            filled += n;
+        }
+    }
+
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), RandError> {
+        self.fill_bytes(dest);
+        Ok(())
+    }
+}